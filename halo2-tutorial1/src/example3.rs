@@ -3,23 +3,49 @@ use std::{marker::PhantomData};
 use halo2_proofs::{
     arithmetic::FieldExt,
     circuit::{AssignedCell, Chip, Layouter, SimpleFloorPlanner},
+    dev::MockProver,
     plonk::*,
     poly::{commitment::Params, commitment::ParamsVerifier, Rotation},
     transcript::{Blake2bRead, Blake2bWrite, Challenge255},
 };
+use pairing::arithmetic::CurveAffine;
 use pairing::bn256::{Bn256, Fr as Fp, G1Affine};
+use pairing::group::ff::PrimeField;
 use rand_core::OsRng;
 
 
 #[derive(Clone, Debug)]
 struct Number<F: FieldExt>(AssignedCell<F, F>);
 
+// Width (in bits) of a single limb fed into the 5-bit `xor_table`.
+const XOR_LIMB_BITS: usize = 5;
+
+// Number of `XOR_LIMB_BITS`-wide limbs needed to cover `width` bits.
+fn num_limbs(width: usize) -> usize {
+    (width + XOR_LIMB_BITS - 1) / XOR_LIMB_BITS
+}
+
+// Extract limb `i` (0 = least significant) of `value`, as seen by the 5-bit xor table.
+fn limb_of<F: FieldExt>(value: F, i: usize) -> F {
+    let value = value.get_lower_128() as u64;
+    F::from((value >> (XOR_LIMB_BITS * i)) & 0b1_1111)
+}
+
 #[derive(Debug, Clone)]
 struct FiboConfig {
     advice: [Column<Advice>; 3],
+    instance: Column<Instance>,
     s_add: Selector,
     s_xor: Selector,
+    s_recompose: Selector,
+    s_range: Selector,
     xor_table: [TableColumn; 3],
+    // word width (bits) XOR'd limb-wise against the 5-bit `xor_table`
+    xor_width: usize,
+    xor_limbs: usize,
+    range_table: TableColumn,
+    // number of bits the range table (and hence `range_check`) bounds values to
+    range_bits: usize,
 }
 
 struct FiboChip<F: FieldExt> {
@@ -53,21 +79,38 @@ impl<F: FieldExt> FiboChip<F> {
     fn configure(
         meta: &mut ConstraintSystem<F>,
         advice: [Column<Advice>; 3],
-        selector: [Selector; 2],
+        instance: Column<Instance>,
+        selector: [Selector; 4],
+        xor_width: usize,
+        range_bits: usize,
     ) -> FiboConfig {
+        // A term can be at most `2^range_bits - 1`, so it must also fit in `xor_width`
+        // bits or `range_check` would accept values the `xor recompose` gate rejects.
+        assert!(
+            xor_width >= range_bits,
+            "xor_width ({}) must be >= range_bits ({})",
+            xor_width,
+            range_bits,
+        );
+
         let s_add = selector[0];
         let s_xor = selector[1];
+        let s_recompose = selector[2];
+        let s_range = selector[3];
+        let xor_limbs = num_limbs(xor_width);
 
         let xor_table = [
             meta.lookup_table_column(),
             meta.lookup_table_column(),
             meta.lookup_table_column(),
         ];
+        let range_table = meta.lookup_table_column();
 
         //check this with an example
         meta.enable_equality(advice[0]);
         meta.enable_equality(advice[1]);
         meta.enable_equality(advice[2]);
+        meta.enable_equality(instance);
 
         meta.lookup("xor", |meta| {
             let s_xor = meta.query_selector(s_xor);
@@ -80,7 +123,32 @@ impl<F: FieldExt> FiboChip<F> {
                 (s_xor * out, xor_table[2]),
             ]
         });
-        //1000 - 10000, sp range check
+
+        // Recompose the `xor_limbs` limb rows directly above the current row into the
+        // full value on each of the three advice columns: value == Σ limb_i * 2^(5*i).
+        meta.create_gate("xor recompose", |meta| {
+            let s_recompose = meta.query_selector(s_recompose);
+            advice
+                .iter()
+                .map(|&column| {
+                    let value = meta.query_advice(column, Rotation::cur());
+                    let composed = (0..xor_limbs)
+                        .map(|i| {
+                            let limb = meta.query_advice(column, Rotation(-((xor_limbs - i) as i32)));
+                            limb * F::from(1u64 << (XOR_LIMB_BITS * i))
+                        })
+                        .fold(Expression::Constant(F::zero()), |acc, term| acc + term);
+                    s_recompose.clone() * (value - composed)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        meta.lookup("range check", |meta| {
+            let s_range = meta.query_selector(s_range);
+            let value = meta.query_advice(advice[0], Rotation::cur());
+            vec![(s_range * value, range_table)]
+        });
+
         meta.create_gate("add", |meta| {
                 let s_add = meta.query_selector(s_add);
                 let lhs = meta.query_advice(advice[0], Rotation::cur());
@@ -90,10 +158,38 @@ impl<F: FieldExt> FiboChip<F> {
         });
 
         FiboConfig {
-            advice, s_add, s_xor, xor_table,
+            advice, instance, s_add, s_xor, s_recompose, s_range,
+            xor_table, xor_width, xor_limbs, range_table, range_bits,
         }
     }
 
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: &Number<F>,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+
+    // Prove `num` lies in `[0, 2^range_bits)` by looking its value up in `range_table`.
+    fn range_check(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: &Number<F>,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || "range check",
+            |mut region| {
+                config.s_range.enable(&mut region, 0)?;
+                num.0.copy_advice(|| "value", &mut region, config.advice[0], 0)?;
+                Ok(())
+            },
+        )
+    }
+
     fn load_private(
         &self,
         mut layouter: impl Layouter<F>,
@@ -168,13 +264,31 @@ impl<F: FieldExt> FiboChip<F> {
         b: &Number<F>,
     ) -> Result<Number<F>, Error> {
         let config = self.config();
+        let limbs = config.xor_limbs;
+
         layouter.assign_region(
             || "xor",
             |mut region| {
-                config.s_xor.enable(&mut region, 0)?;
+                // One row per limb, each looked up against the 5-bit `xor_table`.
+                for i in 0..limbs {
+                    config.s_xor.enable(&mut region, i)?;
+
+                    let lhs_limb = a.0.value().map(|v| limb_of(*v, i));
+                    let rhs_limb = b.0.value().map(|v| limb_of(*v, i));
+                    let out_limb = lhs_limb
+                        .zip(rhs_limb)
+                        .map(|(l, r)| F::from(l.get_lower_128() as u64 ^ r.get_lower_128() as u64));
+
+                    region.assign_advice(|| "lhs limb", config.advice[0], i, || lhs_limb.ok_or(Error::Synthesis))?;
+                    region.assign_advice(|| "rhs limb", config.advice[1], i, || rhs_limb.ok_or(Error::Synthesis))?;
+                    region.assign_advice(|| "out limb", config.advice[2], i, || out_limb.ok_or(Error::Synthesis))?;
+                }
 
-                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], 0)?;
-                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], 0)?;
+                // Recomposition row: full lhs/rhs/out, constrained against the limb rows above.
+                config.s_recompose.enable(&mut region, limbs)?;
+
+                a.0.copy_advice(|| "lhs", &mut region, config.advice[0], limbs)?;
+                b.0.copy_advice(|| "rhs", &mut region, config.advice[1], limbs)?;
 
                 let value = a.0.value().and_then(|a| b.0.value().map(|b| {
                     let a_val = a.get_lower_128() as u64;
@@ -186,7 +300,7 @@ impl<F: FieldExt> FiboChip<F> {
                 region.assign_advice(
                     || "out",
                     config.advice[2],
-                    0,
+                    limbs,
                     || value.ok_or(Error::Synthesis),
                 ).map(Number)
             },
@@ -228,6 +342,49 @@ impl<F: FieldExt> FiboChip<F> {
             }
         )
     }
+
+    fn load_range_table(
+        &self,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let range = 1u64 << self.config.range_bits;
+        layouter.assign_table(
+            || "range check",
+            |mut table| {
+                for value in 0..range {
+                    table.assign_cell(
+                        || "value",
+                        self.config.range_table,
+                        value as usize,
+                        || Ok(F::from(value)),
+                    )?;
+                }
+                Ok(())
+            }
+        )
+    }
+}
+
+// Default XOR word width (bits) for circuits that don't need wider words.
+const DEFAULT_XOR_WIDTH: usize = 10;
+// Default bound (bits) each sequence term is range-checked against. Kept small
+// enough that its `1 << range_bits`-row table still fits alongside the xor table's
+// 1024 rows at the `k` `main`/`bench_batch_verify` derive from `estimate_cost`.
+const DEFAULT_RANGE_BITS: usize = 9;
+
+#[derive(Debug, Clone, Copy)]
+struct FiboParams {
+    xor_width: usize,
+    range_bits: usize,
+}
+
+impl Default for FiboParams {
+    fn default() -> Self {
+        FiboParams {
+            xor_width: DEFAULT_XOR_WIDTH,
+            range_bits: DEFAULT_RANGE_BITS,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -236,26 +393,56 @@ struct FiboCircuit<F> {
     b: F,
     c: F,
     num: usize,
+    params: FiboParams,
 }
 
 impl<F: FieldExt> Circuit<F> for FiboCircuit<F> {
     type Config = FiboConfig;
     type FloorPlanner = SimpleFloorPlanner;
+    type Params = FiboParams;
 
     fn without_witnesses(&self) -> Self {
         Self::default()
     }
 
+    fn params(&self) -> Self::Params {
+        self.params
+    }
+
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        Self::configure_with_params(meta, FiboParams::default())
+    }
+
+    fn configure_with_params(meta: &mut ConstraintSystem<F>, params: Self::Params) -> Self::Config {
         let advice = [
             meta.advice_column(),
             meta.advice_column(),
             meta.advice_column(),
         ];
-        let selector = [meta.selector(), meta.complex_selector()];
-        FiboChip::configure(meta, advice, selector)
+        let instance = meta.instance_column();
+        let selector = [
+            meta.selector(),
+            meta.complex_selector(),
+            meta.selector(),
+            meta.complex_selector(),
+        ];
+        FiboChip::configure(meta, advice, instance, selector, params.xor_width, params.range_bits)
     }
 
+    // WON'T FIX (chunk0-6): round synthesis stays serial, on purpose. It was asked to
+    // assign each round's advice cells concurrently, but that's blocked on two
+    // independent fronts:
+    //   1. The recurrence itself is sequential: round i's `new_c = a + (b ^ c)` reads
+    //      round (i-1)'s `b`/`c`, so rounds can't be computed independently of each
+    //      other regardless of how they're assigned.
+    //   2. Even if the witness values could be precomputed out of order, assigning them
+    //      still goes through `Layouter::assign_region(&mut self, ..)` — `SimpleFloorPlanner`
+    //      gives no way to hold two concurrent `&mut` borrows of the layouter, so the
+    //      assignment calls below must run one after another no matter what.
+    // An earlier attempt (`parallel_syn`) precomputed round outputs on a thread pool but
+    // still had `xor`/`add` recompute those same values from the `AssignedCell`s during
+    // the (necessarily serial) assignment pass — net zero parallelism for the cost of a
+    // thread spawn per round. It was reverted rather than kept as dead-weight plumbing.
     fn synthesize(
         &self,
         config: Self::Config,
@@ -263,6 +450,7 @@ impl<F: FieldExt> Circuit<F> for FiboCircuit<F> {
     ) -> Result<(), Error> {
         let chip = FiboChip::construct(config);
         chip.load_table(layouter.namespace(|| "lookup table"))?;
+        chip.load_range_table(layouter.namespace(|| "range table"))?;
 
         let (mut a, mut b, mut c) = chip.load_private(
             layouter.namespace(|| "first row"),
@@ -270,6 +458,7 @@ impl<F: FieldExt> Circuit<F> for FiboCircuit<F> {
             self.b,
             self.c,
         )?;
+        chip.range_check(layouter.namespace(|| "range check c"), &c)?;
 
         for _ in 3..self.num {
             let xor = chip.xor(
@@ -282,14 +471,126 @@ impl<F: FieldExt> Circuit<F> for FiboCircuit<F> {
                 &a,
                 &xor,
             )?;
+            chip.range_check(layouter.namespace(|| "range check c"), &new_c)?;
             a = b;
             b = c;
             c = new_c;
         }
+
+        chip.expose_public(layouter.namespace(|| "expose c"), &c, 0)?;
+
         Ok(())
     }
 }
 
+// ABI-encode the `(bytes proof, uint256[] instances)` argument tuple the way Solidity's
+// `abi.encode` would: a head of one 32-byte offset per dynamic argument, followed by
+// each argument's tail (a length word, then its data word-padded to 32 bytes). `instances`'
+// columns are flattened into the single `uint256[]` `FiboVerifierScaffold.verify` takes.
+//
+// This does NOT prepend the 4-byte function selector real calldata needs — computing
+// `keccak256("verify(bytes,uint256[])")` honestly needs a keccak implementation, and this
+// tree has no `Cargo.toml`/dependencies at all to provide one. Callers append the
+// selector themselves (e.g. `cast sig "verify(bytes,uint256[])"`) before this payload.
+fn encode_calldata(proof: &[u8], instances: &[&[Fp]]) -> Vec<u8> {
+    fn word(value: usize) -> [u8; 32] {
+        let mut w = [0u8; 32];
+        w[24..].copy_from_slice(&(value as u64).to_be_bytes());
+        w
+    }
+
+    fn right_padded_to_word(data: &[u8]) -> Vec<u8> {
+        let mut out = data.to_vec();
+        let pad = (32 - out.len() % 32) % 32;
+        out.extend(std::iter::repeat(0u8).take(pad));
+        out
+    }
+
+    let flat_instances: Vec<Fp> = instances
+        .iter()
+        .flat_map(|column| column.iter().copied())
+        .collect();
+
+    let mut proof_tail = word(proof.len()).to_vec();
+    proof_tail.extend(right_padded_to_word(proof));
+
+    let mut instances_tail = word(flat_instances.len()).to_vec();
+    for value in &flat_instances {
+        let mut repr = value.to_repr();
+        repr.as_mut().reverse(); // little-endian repr -> big-endian EVM word
+        instances_tail.extend_from_slice(repr.as_ref());
+    }
+
+    let head_size = 64; // one offset word per dynamic argument (proof, instances)
+    let offset_proof = head_size;
+    let offset_instances = head_size + proof_tail.len();
+
+    let mut calldata = Vec::new();
+    calldata.extend_from_slice(&word(offset_proof));
+    calldata.extend_from_slice(&word(offset_instances));
+    calldata.extend_from_slice(&proof_tail);
+    calldata.extend_from_slice(&instances_tail);
+    calldata
+}
+
+// DESCOPED (chunk0-7): this renders a Solidity scaffold embedding KZG/BN256 points
+// (`params_verifier`'s G2 and S_G2, and `vk`'s first fixed commitment) — it does NOT implement the
+// pairing/MSM opening check the request asked for, and is not a completed on-chain
+// verifier. `FiboVerifierScaffold.verify` reverts unconditionally rather than folding in
+// `instances`, the permutation/lookup commitments, and the verifier's Fiat-Shamir
+// challenges the way `verify_proof` does off-chain: reproducing that MSM/pairing
+// equation in Solidity needs this fork's transcript and opening-argument internals,
+// which aren't exposed by the public API this file otherwise relies on. The contract is
+// named `...Scaffold` and reverts with an explicit message so it can't be mistaken for
+// a working verifier.
+fn render_verifier(vk: &VerifyingKey<G1Affine>, params_verifier: &ParamsVerifier<Bn256>) -> String {
+    let fmt_g1 = |p: &G1Affine| {
+        let coords = p.coordinates().unwrap();
+        format!("Pairing.G1Point({}, {})", coords.x(), coords.y())
+    };
+    let fmt_g2 = |p: &pairing::bn256::G2Affine| {
+        let coords = p.coordinates().unwrap();
+        format!(
+            "Pairing.G2Point([{}, {}], [{}, {}])",
+            coords.x().c1, coords.x().c0, coords.y().c1, coords.y().c0,
+        )
+    };
+
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+import "./Pairing.sol";
+
+// SCAFFOLD ONLY — NOT A WORKING VERIFIER. Embeds this circuit's verifying-key points
+// but does not implement the KZG opening check `verify_proof` performs off-chain.
+// `verify` reverts instead of returning a meaningless pairing result; fill in the real
+// MSM/pairing equation (folding `instances`, the permutation/lookup commitments, and
+// the transcript's Fiat-Shamir challenges) before relying on this on-chain.
+contract FiboVerifierScaffold {{
+    using Pairing for *;
+
+    Pairing.G2Point G2 = {g2};
+    Pairing.G2Point S_G2 = {s_g2};
+    Pairing.G1Point FIXED_COMMITMENT_0 = {fixed_commitment};
+
+    function verify(bytes calldata proof, uint256[] calldata instances) external pure returns (bool) {{
+        proof;
+        instances;
+        revert("FiboVerifierScaffold: opening check not implemented, see contract comment");
+    }}
+}}
+"#,
+        g2 = fmt_g2(&params_verifier.g2),
+        s_g2 = fmt_g2(&params_verifier.s_g2),
+        fixed_commitment = vk
+            .fixed_commitments()
+            .first()
+            .map(fmt_g1)
+            .unwrap_or_else(|| "Pairing.G1Point(0, 0)".to_string()),
+    )
+}
+
 fn get_sequence(a: u64, b: u64, c: u64, num: usize) -> Vec<u64> {
     let mut seq = vec![0; num];
     seq[0] = a;
@@ -301,6 +602,207 @@ fn get_sequence(a: u64, b: u64, c: u64, num: usize) -> Vec<u64> {
     seq
 }
 
+// Estimated prover cost of a `FiboCircuit`, tallied without doing any real field work:
+// column/gate/lookup counts come straight off the shape of `FiboChip::configure`, and
+// row counts come off `synthesize`'s fixed region layout (one region per `add`/`xor`/
+// `range_check` call), so this only needs the circuit's public parameters.
+//
+// This mirrors `synthesize`'s layout by formula rather than tallying an actual layouter
+// pass, because there is no way to write a counting `Layouter` against the public API:
+// `halo2_proofs`'s `Region`/`Table` types are only constructible from inside that crate.
+// Treat `minimal_k` as a starting guess, not a guarantee — callers that turn it into a
+// real `k` should validate it with [`assert_minimal_k`] first, so a layout change this
+// formula misses fails loudly there instead of silently inside `create_proof`.
+#[derive(Debug, Clone)]
+struct CostReport {
+    advice_columns: usize,
+    instance_columns: usize,
+    gate_count: usize,
+    lookup_count: usize,
+    xor_table_rows: usize,
+    range_table_rows: usize,
+    rows_per_add: usize,
+    rows_per_xor: usize,
+    rows_per_range_check: usize,
+    circuit_rows: usize,
+    minimal_k: u32,
+}
+
+// halo2 reserves some rows at the end of every column for blinding factors, so the
+// usable row count at a given `k` is `2^k - RESERVED_BLINDING_ROWS`, not `2^k`. This
+// is a conservative margin (actual reserved rows depend on the max gate/lookup
+// degree) so `minimal_k` doesn't undercount and hit `NotEnoughRowsAvailable`.
+const RESERVED_BLINDING_ROWS: usize = 16;
+
+fn estimate_cost<F>(circuit: &FiboCircuit<F>) -> CostReport {
+    let steps = circuit.num.saturating_sub(3);
+    let xor_limbs = num_limbs(circuit.params.xor_width);
+
+    let rows_per_add = 1;
+    let rows_per_xor = xor_limbs + 1; // xor_limbs limb rows + 1 recomposition row
+    let rows_per_range_check = 1;
+
+    let xor_table_rows = 32 * 32;
+    let range_table_rows = 1usize << circuit.params.range_bits;
+
+    // load_private's first row, the range check on it, then `steps` xor+add+range_check regions.
+    let circuit_rows = 1
+        + rows_per_range_check
+        + steps * (rows_per_xor + rows_per_add + rows_per_range_check);
+
+    // Largest of the circuit's own rows and its two lookup tables dictates the minimal `k`,
+    // accounting for the rows blinding reserves at the end of every column.
+    let rows_needed = circuit_rows.max(xor_table_rows).max(range_table_rows);
+    let mut minimal_k = 1u32;
+    while (1usize << minimal_k).saturating_sub(RESERVED_BLINDING_ROWS) < rows_needed {
+        minimal_k += 1;
+    }
+
+    CostReport {
+        advice_columns: 3,
+        instance_columns: 1,
+        gate_count: 2,  // "xor recompose", "add"
+        lookup_count: 2, // "xor", "range check"
+        xor_table_rows,
+        range_table_rows,
+        rows_per_add,
+        rows_per_xor,
+        rows_per_range_check,
+        circuit_rows,
+        minimal_k,
+    }
+}
+
+// Check `cost.minimal_k` against a real synthesis of `circuit`, by actually running it
+// through `MockProver` rather than trusting `estimate_cost`'s formulas. If `synthesize`'s
+// region layout ever changes in a way the formulas don't account for, this panics with
+// `NotEnoughRowsAvailable` (or a constraint mismatch) right here, instead of the formula
+// silently drifting until `create_proof`/`keygen_vk` fail deep inside a real proving run.
+fn assert_minimal_k<F: FieldExt + Ord>(circuit: &FiboCircuit<F>, public_input: F, cost: &CostReport) {
+    MockProver::run(cost.minimal_k, circuit, vec![vec![public_input]])
+        .expect("estimate_cost's minimal_k should be enough rows to synthesize the circuit")
+        .verify()
+        .expect("estimate_cost's minimal_k drifted from the circuit's real row usage");
+}
+
+// Verify many independently-generated `FiboCircuit` proofs together. Each proof's
+// transcript contributes its own Fiat-Shamir challenges, and `BatchVerifier`
+// accumulates every proof's opening into one multi-scalar-commitment, so the whole
+// batch costs a single final pairing instead of one pairing per proof. The batch is
+// rejected as a whole if any single proof is invalid.
+fn verify_batch(
+    params_verifier: &ParamsVerifier<Bn256>,
+    vk: &VerifyingKey<G1Affine>,
+    proofs: &[(Vec<u8>, Fp)],
+) -> Result<(), Error> {
+    let mut strategy = BatchVerifier::new(params_verifier);
+
+    for (proof, public_input) in proofs {
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
+        strategy = verify_proof(
+            params_verifier,
+            vk,
+            strategy,
+            &[&[&[*public_input]]],
+            &mut transcript,
+        )?;
+    }
+
+    if strategy.finalize() {
+        Ok(())
+    } else {
+        Err(Error::Opening)
+    }
+}
+
+// Build and prove `n` `FiboCircuit`s over distinct seeds, then compare the cost of
+// verifying them with `verify_batch` against looping `verify_proof` one proof at a
+// time, to show where the batched pairing amortizes.
+fn bench_batch_verify(n: usize) {
+    let num = 14;
+    let seed_seq = get_sequence(1, 3, 2, num);
+    let seed_circuit = FiboCircuit::<Fp> {
+        a: Fp::from(seed_seq[0]),
+        b: Fp::from(seed_seq[1]),
+        c: Fp::from(seed_seq[2]),
+        num,
+        params: FiboParams::default(),
+    };
+
+    // Derive `k` from the circuit's own cost estimate instead of hardcoding it, so it
+    // always fits the xor/range tables the configured `params` actually need, then check
+    // the estimate against a real synthesis before trusting it for keygen/proving below.
+    let cost = estimate_cost(&seed_circuit);
+    let k = cost.minimal_k;
+    assert_minimal_k(&seed_circuit, Fp::from(seed_seq[num - 1]), &cost);
+    let params: Params<G1Affine> = Params::<G1Affine>::unsafe_setup::<Bn256>(k);
+    let params_verifier: ParamsVerifier<Bn256> = params.verifier(0).unwrap();
+
+    let vk = keygen_vk(&params, &seed_circuit).expect("keygen_vk should not fail");
+    let pk = keygen_pk(&params, vk, &seed_circuit).expect("keygen_pk should not fail");
+
+    let proofs: Vec<(Vec<u8>, Fp)> = (0..n)
+        .map(|i| {
+            let seed = (1 + i as u64, 3 + i as u64, 2 + i as u64);
+            let seq = get_sequence(seed.0, seed.1, seed.2, num);
+            let circuit = FiboCircuit {
+                a: Fp::from(seq[0]),
+                b: Fp::from(seq[1]),
+                c: Fp::from(seq[2]),
+                num,
+                params: FiboParams::default(),
+            };
+            let public_input = Fp::from(seq[num - 1]);
+
+            let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
+            create_proof(
+                &params,
+                &pk,
+                &[circuit],
+                &[&[&[public_input]]],
+                OsRng,
+                &mut transcript,
+            )
+            .expect("proof generation should not fail");
+
+            (transcript.finalize(), public_input)
+        })
+        .collect();
+
+    let start = std::time::Instant::now();
+    for (proof, public_input) in &proofs {
+        let strategy = SingleVerifier::new(&params_verifier);
+        let mut transcript = Blake2bRead::<_, _, Challenge255<_>>::init(&proof[..]);
+        verify_proof(
+            &params_verifier,
+            pk.get_vk(),
+            strategy,
+            &[&[&[*public_input]]],
+            &mut transcript,
+        )
+        .expect("individual verification should not fail");
+    }
+    println!("looped verify_proof over {} proofs: {:?}", n, start.elapsed());
+
+    let start = std::time::Instant::now();
+    verify_batch(&params_verifier, pk.get_vk(), &proofs)
+        .expect("batch verification should not fail");
+    println!("verify_batch over {} proofs: {:?}", n, start.elapsed());
+}
+
+// Write the ABI-encoded calldata and the Solidity verifier scaffold for a finalized
+// proof to disk. The `.sol` file is a non-functional scaffold (see `render_verifier`),
+// not a working on-chain verifier.
+fn write_evm_artifacts(
+    vk: &VerifyingKey<G1Affine>,
+    params_verifier: &ParamsVerifier<Bn256>,
+    proof: &[u8],
+    instances: &[&[Fp]],
+) -> std::io::Result<()> {
+    std::fs::write("fibo_calldata.bin", encode_calldata(proof, instances))?;
+    std::fs::write("FiboVerifierScaffold.sol", render_verifier(vk, params_verifier))
+}
+
 fn main() {
     // Prepare the private and public inputs to the circuit!
     let num = 14;
@@ -313,10 +815,20 @@ fn main() {
         b: Fp::from(seq[1]),
         c: Fp::from(seq[2]),
         num,
+        params: FiboParams::default(),
     };
 
-    // Set circuit size
-    let k = 11;
+    let cost = estimate_cost(&circuit);
+    println!("{:#?}", cost);
+
+    // The public instance: the claimed last term of the sequence.
+    let public_input = Fp::from(seq[num - 1]);
+
+    // Set circuit size to whatever the cost estimate says is minimally sufficient, so it
+    // always fits the xor/range tables `circuit.params` actually needs, then check that
+    // estimate against a real synthesis before trusting it for keygen/proving below.
+    let k = cost.minimal_k;
+    assert_minimal_k(&circuit, public_input, &cost);
 
     // Initialize the polynomial commitment parameters
     let params: Params<G1Affine> = Params::<G1Affine>::unsafe_setup::<Bn256>(k);
@@ -329,8 +841,15 @@ fn main() {
     // Create a proof
     let mut transcript = Blake2bWrite::<_, _, Challenge255<_>>::init(vec![]);
 
-    create_proof(&params, &pk, &[circuit], &[&[]], OsRng, &mut transcript)
-        .expect("proof generation should not fail");
+    create_proof(
+        &params,
+        &pk,
+        &[circuit],
+        &[&[&[public_input]]],
+        OsRng,
+        &mut transcript,
+    )
+    .expect("proof generation should not fail");
 
     let proof = transcript.finalize();
     println!("proof size is {}", proof.len());
@@ -343,8 +862,13 @@ fn main() {
         &params_verifier,
         pk.get_vk(),
         strategy,
-        &[&[]],
+        &[&[&[public_input]]],
         &mut transcript,
     )
     .unwrap();
+
+    write_evm_artifacts(pk.get_vk(), &params_verifier, &proof, &[&[public_input]])
+        .expect("writing EVM artifacts should not fail");
+
+    bench_batch_verify(4);
 }